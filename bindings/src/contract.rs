@@ -1,5 +1,7 @@
 use crate::addresses::erc20_forwarder_address;
-use crate::generated::erc20_forwarder::ERC20Forwarder::ERC20ForwarderInstance;
+use crate::generated::erc20_forwarder::ERC20Forwarder::{
+    ERC20ForwarderErrors, ERC20ForwarderInstance,
+};
 use alloy::providers::{DynProvider, Provider};
 use alloy_chains::NamedChain;
 use serde::Serialize;
@@ -35,3 +37,9 @@ pub async fn erc20_forwarder(
         None => Err(BindingsError::UnsupportedChain(named_chain.to_string())),
     }
 }
+
+/// Returns the name of the ERC20 forwarder custom error encoded in the provided revert data, if any.
+pub fn forwarder_revert_reason(revert_data: &[u8]) -> Option<&'static str> {
+    let selector: [u8; 4] = revert_data.get(..4)?.try_into().ok()?;
+    ERC20ForwarderErrors::name_by_selector(selector)
+}
@@ -3,12 +3,15 @@ extern crate dotenvy;
 
 use alloy::primitives::{Address, b256};
 use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::sol_types::SolError;
 use alloy_chains::NamedChain;
 use anoma_pa_evm_bindings::addresses::protocol_adapter_address;
 use anoma_pa_evm_bindings::helpers::rpc_url;
 use anomapay_erc20_forwarder_bindings::addresses::erc20_forwarder_deployments_map;
-use anomapay_erc20_forwarder_bindings::contract::erc20_forwarder;
-use anomapay_erc20_forwarder_bindings::generated::erc20_forwarder::ERC20Forwarder::ERC20ForwarderInstance;
+use anomapay_erc20_forwarder_bindings::contract::{erc20_forwarder, forwarder_revert_reason};
+use anomapay_erc20_forwarder_bindings::generated::erc20_forwarder::ERC20Forwarder::{
+    ERC20ForwarderInstance, UnauthorizedCaller, ZeroNotAllowed,
+};
 
 #[tokio::test]
 async fn deployed_forwarders_point_to_the_current_protocol_adapter_contract() {
@@ -55,6 +58,28 @@ async fn deployed_forwarders_reference_the_expected_logic_ref() {
     }
 }
 
+#[test]
+fn revert_reason_is_decoded_from_forwarder_custom_errors() {
+    let revert_data = UnauthorizedCaller {
+        expected: Address::ZERO,
+        actual: Address::repeat_byte(1),
+    }
+    .abi_encode();
+    assert_eq!(
+        forwarder_revert_reason(&revert_data),
+        Some("UnauthorizedCaller")
+    );
+
+    assert_eq!(
+        forwarder_revert_reason(&ZeroNotAllowed.abi_encode()),
+        Some("ZeroNotAllowed")
+    );
+
+    // Unknown selectors and truncated revert data are not decoded.
+    assert_eq!(forwarder_revert_reason(&[0xde, 0xad, 0xbe, 0xef]), None);
+    assert_eq!(forwarder_revert_reason(&[0xde, 0xad]), None);
+}
+
 async fn fwd_instance(chain: &NamedChain) -> ERC20ForwarderInstance<DynProvider> {
     let rpc_url = rpc_url(chain).unwrap();
 